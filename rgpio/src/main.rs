@@ -3,7 +3,7 @@ use std::{thread, time};
 use std::error::Error;
 use rgpiolib::gpio;
 
-fn main() -> Result<(), dyn Error> {
+fn main() -> Result<(), Box<dyn Error>> {
     // Collect command-line arguments into a vector of strings
     let args: Vec<String> = env::args().collect();
 
@@ -20,7 +20,7 @@ fn main() -> Result<(), dyn Error> {
             };
 
             // Export the GPIO pin and set its direction to output
-            if (gpio::export(gpio_num).is_ok()) {
+            if gpio::export(gpio_num).is_ok() {
                 gpio::set_direction(gpio_num, gpio::Directions::Output)?;
                 gpio::write(gpio_num, true)?;
 
@@ -37,51 +37,90 @@ pub mod gpio {
     use std::{fmt, fs};
     use std::fs::File;
     use std::io::prelude::*;
+    use std::io::SeekFrom;
+    use std::os::unix::io::AsRawFd;
     use std::path::Path;
+    use std::thread;
+    use std::time::Duration;
 
     /// GPIO paths
     ///
     /// This enum represents the paths to the GPIO files.
     enum GpioPaths {
         /// Export GPIO pin
-        EXPORT,
+        Export,
         /// Unexport GPIO pin
-        UNEXPORT,
+        Unexport,
         /// Value of GPIO pin
-        VALUE(i32),
+        Value(i32),
         /// Direction of GPIO pin
-        DIRECTION(i32),
+        Direction(i32),
+        /// Edge trigger of GPIO pin
+        Edge(i32),
+        /// Active-low polarity of GPIO pin
+        ActiveLow(i32),
     }
 
-    /// Implement the `as_str` method for the `GpioPaths` enum.
+    /// Implement the `path` method for the `GpioPaths` enum.
     impl GpioPaths {
-        /// Returns the path as a string slice.
+        /// Returns the path as an owned `String`.
+        ///
+        /// Paths that embed the pin number are built on the fly with
+        /// `format!` and returned by value, so no memory is leaked.
+        pub fn path(&self) -> String {
+            match *self {
+                GpioPaths::Export => "/sys/class/gpio/export".to_string(),
+                GpioPaths::Unexport => "/sys/class/gpio/unexport".to_string(),
+                GpioPaths::Value(num) => format!("/sys/class/gpio/gpio{}/value", num),
+                GpioPaths::Direction(num) => format!("/sys/class/gpio/gpio{}/direction", num),
+                GpioPaths::Edge(num) => format!("/sys/class/gpio/gpio{}/edge", num),
+                GpioPaths::ActiveLow(num) => format!("/sys/class/gpio/gpio{}/active_low", num),
+            }
+        }
+    }
+
+    /// GPIO edge trigger
+    ///
+    /// This enum represents the edge transitions that [`wait_for_edge`] can
+    /// block on, mirrored onto the sysfs `edge` file's `none`/`rising`/
+    /// `falling`/`both` values.
+    pub enum Edge {
+        None,
+        Rising,
+        Falling,
+        Both,
+    }
+
+    /// Implement the `as_str` method for the `Edge` enum.
+    impl Edge {
+        /// Returns the edge as a string slice.
         pub fn as_str(&self) -> &'static str {
             match *self {
-                /// Path to export GPIO pin
-                GpioPaths::EXPORT => "/sys/class/gpio/export",
-                /// Path to unexport GPIO pin
-                GpioPaths::UNEXPORT => "/sys/class/gpio/unexport",
-                /// Path to value of GPIO pin
-                GpioPaths::VALUE(num) => {
-                    let path = format!("/sys/class/gpio/gpio{}/value", num);
-                    Box::leak(path.into_boxed_str())
-                },
-                /// Path to direction of GPIO pin
-                GpioPaths::DIRECTION(num) => {
-                    let path = format!("/sys/class/gpio/gpio{}/direction", num);
-                    Box::leak(path.into_boxed_str())
-                },
+                Edge::None => "none",
+                Edge::Rising => "rising",
+                Edge::Falling => "falling",
+                Edge::Both => "both",
             }
         }
+
+        /// Returns the edge as a byte slice.
+        pub fn as_bytes(&self) -> &[u8] {
+            self.as_str().as_bytes()
+        }
     }
 
     /// GPIO directions
     ///
-    /// This enum represents the directions of a GPIO pin.
+    /// This enum represents the directions of a GPIO pin, mirroring the
+    /// kernel's `in | out | low | high` direction model. `OutputLow` and
+    /// `OutputHigh` configure the pin as output and set its starting level in
+    /// a single write, avoiding the glitch that `set_direction(Output)`
+    /// followed by a separate `write` can produce.
     ///
     /// - Input
     /// - Output
+    /// - OutputLow
+    /// - OutputHigh
     pub type Directions = directions::Directions;
 
     /// Implement the `as_str` method for the `Directions` enum.
@@ -89,9 +128,14 @@ pub mod gpio {
         /// GPIO directions
         ///
         /// This enum represents the directions of a GPIO pin.
+        #[derive(Debug, PartialEq)]
         pub enum Directions {
             Input,
             Output,
+            /// Output, driven low immediately upon direction change.
+            OutputLow,
+            /// Output, driven high immediately upon direction change.
+            OutputHigh,
         }
 
         /// Implement the `as_str` method for the `Directions` enum.
@@ -101,6 +145,8 @@ pub mod gpio {
                 match *self {
                     Directions::Input => "in",
                     Directions::Output => "out",
+                    Directions::OutputLow => "low",
+                    Directions::OutputHigh => "high",
                 }
             }
 
@@ -121,6 +167,8 @@ pub mod gpio {
     pub enum GpioError {
         Io(std::io::Error),
         ParseInt(std::num::ParseIntError),
+        Timeout,
+        PermissionRetryExhausted,
     }
 
     /// Implement the `Display` trait for the `GpioError` enum.
@@ -131,10 +179,21 @@ pub mod gpio {
             match *self {
                 GpioError::Io(ref err) => write!(f, "IO error: {}", err),
                 GpioError::ParseInt(ref err) => write!(f, "ParseInt error: {}", err),
+                GpioError::Timeout => write!(f, "operation timed out"),
+                GpioError::PermissionRetryExhausted => write!(
+                    f,
+                    "gave up waiting for udev to fix up the GPIO file's permissions"
+                ),
             }
         }
     }
 
+    /// Implement the `std::error::Error` trait for the `GpioError` enum.
+    ///
+    /// This lets `GpioError` convert into `Box<dyn std::error::Error>` via
+    /// `?`, which callers like the `rgpio` binary rely on.
+    impl std::error::Error for GpioError {}
+
     /// Implement the `From<std::io::Error>` trait for the `GpioError` enum.
     impl From<std::io::Error> for GpioError {
         fn from(err: std::io::Error) -> GpioError {
@@ -156,7 +215,7 @@ pub mod gpio {
 
     /// Open file
     ///
-    /// This function opens a file and returns a file handle.
+    /// This function opens a file for writing and returns a file handle.
     ///
     /// # Arguments
     ///
@@ -165,11 +224,53 @@ pub mod gpio {
     /// # Returns
     ///
     /// A `GpioResult` that contains a file handle.
-    fn open_file(filepath: &'static str) -> GpioResult<File> {
-        let path = Path::new(&filepath);
+    fn open_file(filepath: &str) -> GpioResult<File> {
+        let path = Path::new(filepath);
         Ok(fs::OpenOptions::new().write(true).open(path)?)
     }
 
+    /// Open a GPIO file for both reading and writing.
+    ///
+    /// Used by `Pin` to cache `value`/`direction` handles so they can be
+    /// `seek`ed back to the start and reused instead of reopened on every call.
+    fn open_file_rw(filepath: &str) -> GpioResult<File> {
+        let path = Path::new(filepath);
+        Ok(fs::OpenOptions::new().read(true).write(true).open(path)?)
+    }
+
+    /// Retry a file open against the Raspbian `value`-file permission race.
+    ///
+    /// Immediately after a pin's `direction` is written, udev briefly hasn't
+    /// fixed up the newly created `value` file's group write permission yet,
+    /// so the first open-for-write can fail with `EACCES`. Retries `attempt`
+    /// with exponential backoff (1ms, 2ms, 4ms, ...) capped so the total time
+    /// spent sleeping never exceeds ~200ms before giving up.
+    fn retry_permission_race<F>(mut attempt: F) -> GpioResult<File>
+    where
+        F: FnMut() -> GpioResult<File>,
+    {
+        let max_total = Duration::from_millis(200);
+        let mut delay = Duration::from_millis(1);
+        let mut elapsed = Duration::from_millis(0);
+
+        loop {
+            match attempt() {
+                Ok(file) => return Ok(file),
+                Err(GpioError::Io(ref err)) if err.kind() == std::io::ErrorKind::PermissionDenied => {
+                    let remaining = match max_total.checked_sub(elapsed) {
+                        Some(remaining) if !remaining.is_zero() => remaining,
+                        _ => return Err(GpioError::PermissionRetryExhausted),
+                    };
+                    let sleep_for = std::cmp::min(delay, remaining);
+                    thread::sleep(sleep_for);
+                    elapsed += sleep_for;
+                    delay *= 2;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
     /// Export GPIO pin
     ///
     /// This function exports (enables) a GPIO pin. The GPIO pin is
@@ -200,7 +301,7 @@ pub mod gpio {
     ///
     /// This function should be called before setting the direction of the GPIO pin.
     pub fn export(gpio_num: i32) -> GpioResult<()> {
-       open_file(GpioPaths::EXPORT.as_str()).and_then(|mut file| {
+       open_file(&GpioPaths::Export.path()).and_then(|mut file| {
             file.write_all(gpio_num.to_string().as_bytes()).map_err(|why| {
                 GpioError::Io(why)
             })
@@ -237,7 +338,7 @@ pub mod gpio {
     ///
     /// This function should be called after exporting the GPIO pin.
     pub fn unexport(gpio_num: i32) -> GpioResult<()> {
-        open_file(GpioPaths::UNEXPORT.as_str()).and_then(|mut file| {
+        open_file(&GpioPaths::Unexport.path()).and_then(|mut file| {
             file.write_all(gpio_num.to_string().as_bytes()).map_err(|why| {
                 GpioError::Io(why)
             })
@@ -258,12 +359,19 @@ pub mod gpio {
     /// # Returns
     ///
     /// A `GpioResult` that contains the result of the operation.
+    ///
+    /// # Note
+    ///
+    /// This reopens the `value` file on every call. Prefer [`Pin::write`] when
+    /// writing repeatedly, since it keeps the file handle open. Opening the
+    /// `value` file for writing retries through the permission race
+    /// described on [`retry_permission_race`] instead of failing immediately.
     pub fn write(gpio_num: i32, signal: bool) -> GpioResult<()> {
-        Ok(
-            open_file(GpioPaths::VALUE(gpio_num).as_str()).and_then(|mut file| {
-                file.write_all(signal.to_string().as_bytes()).map_err(GpioError::from)
-            })?
-        )
+        let path = GpioPaths::Value(gpio_num).path();
+        let bytes: &[u8] = if signal { b"1" } else { b"0" };
+        retry_permission_race(|| open_file(&path)).and_then(|mut file| {
+            file.write_all(bytes).map_err(GpioError::from)
+        })
     }
 
     /// Read from GPIO pin
@@ -278,9 +386,14 @@ pub mod gpio {
     /// # Returns
     ///
     /// A `GpioResult` that contains the signal read from the GPIO pin.
+    ///
+    /// # Note
+    ///
+    /// This reopens the `value` file on every call. Prefer [`Pin::read`] when
+    /// reading repeatedly, since it keeps the file handle open.
     pub fn read(gpio_num: i32) -> GpioResult<bool> {
-        let value = fs::read_to_string(GpioPaths::VALUE(gpio_num).as_str()).and_then(|contents| {
-           match contents.parse::<i32>() {
+        let value = fs::read_to_string(GpioPaths::Value(gpio_num).path()).and_then(|contents| {
+           match contents.trim().parse::<i32>() {
                 Ok(val) => Ok(val),
                 Err(why) => Err(std::io::Error::new(std::io::ErrorKind::InvalidData, why)),
            }
@@ -318,9 +431,410 @@ pub mod gpio {
     /// # Note
     ///
     /// This function should be called after exporting the GPIO pin.
+    ///
+    /// Opening the `direction` file for writing retries through the udev
+    /// permission race described on [`retry_permission_race`] instead of
+    /// failing immediately.
     pub fn set_direction(gpio_num: i32, direction: Directions) -> GpioResult<()> {
-        open_file(GpioPaths::DIRECTION(gpio_num).as_str()).and_then(|mut file| {
+        let path = GpioPaths::Direction(gpio_num).path();
+        retry_permission_race(|| open_file(&path)).and_then(|mut file| {
             file.write_all(direction.as_bytes()).map_err(GpioError::from)
         })
     }
-}
\ No newline at end of file
+
+    /// Set GPIO pin edge trigger
+    ///
+    /// This function configures which input transitions [`wait_for_edge`]
+    /// blocks on. The edge is passed as an argument. The function writes the
+    /// edge to the `/sys/class/gpio/gpio{num}/edge` file.
+    ///
+    /// # Arguments
+    ///
+    /// - `gpio_num` - An integer that represents the GPIO pin number.
+    /// - `edge` - An `Edge` enum that represents the edge to trigger on.
+    ///
+    /// # Returns
+    ///
+    /// A `GpioResult` that contains the result of the operation.
+    ///
+    /// # Note
+    ///
+    /// The pin must be set to `Input` direction for edge triggering to apply.
+    /// Opening the `edge` file for writing retries through the udev
+    /// permission race described on [`retry_permission_race`] instead of
+    /// failing immediately.
+    pub fn set_edge(gpio_num: i32, edge: Edge) -> GpioResult<()> {
+        let path = GpioPaths::Edge(gpio_num).path();
+        retry_permission_race(|| open_file(&path)).and_then(|mut file| {
+            file.write_all(edge.as_bytes()).map_err(GpioError::from)
+        })
+    }
+
+    /// Block until a GPIO pin transitions, or a timeout elapses.
+    ///
+    /// This function opens the pin's `value` file, discards the current
+    /// state so a transition already in flight isn't mistaken for a fresh
+    /// one, then calls `poll(2)` for `POLLPRI | POLLERR` on its file
+    /// descriptor. The sysfs `value` file signals edge events through
+    /// exceptional conditions rather than readability, which is why `POLLIN`
+    /// alone would never wake up.
+    ///
+    /// # Arguments
+    ///
+    /// - `gpio_num` - An integer that represents the GPIO pin number.
+    /// - `timeout` - An optional `Duration` to wait before giving up. `None`
+    ///   blocks indefinitely.
+    ///
+    /// # Returns
+    ///
+    /// A `GpioResult` that contains the signal observed after the wakeup, or
+    /// `GpioError::Timeout` if `timeout` elapsed with no transition.
+    ///
+    /// # Note
+    ///
+    /// Call [`set_edge`] beforehand to select which transitions wake this up.
+    /// Opening the `value` file retries through the udev permission race
+    /// described on [`retry_permission_race`] instead of failing immediately.
+    pub fn wait_for_edge(gpio_num: i32, timeout: Option<Duration>) -> GpioResult<bool> {
+        let path = GpioPaths::Value(gpio_num).path();
+        let mut file = retry_permission_race(|| open_file_rw(&path))?;
+
+        // Dummy read to clear the initial state before we start polling for
+        // the *next* transition.
+        let mut initial = String::new();
+        file.read_to_string(&mut initial)?;
+        file.seek(SeekFrom::Start(0))?;
+
+        let timeout_ms: libc::c_int = match timeout {
+            Some(duration) => duration.as_millis() as libc::c_int,
+            None => -1,
+        };
+
+        let mut pfd = libc::pollfd {
+            fd: file.as_raw_fd(),
+            events: libc::POLLPRI | libc::POLLERR,
+            revents: 0,
+        };
+
+        // A stray signal (e.g. SIGCHLD in any process that forks) can
+        // interrupt poll() with EINTR; that's not a real timeout or error,
+        // so just poll again.
+        loop {
+            let ret = unsafe { libc::poll(&mut pfd, 1, timeout_ms) };
+            if ret == 0 {
+                return Err(GpioError::Timeout);
+            } else if ret < 0 {
+                let err = std::io::Error::last_os_error();
+                if err.kind() == std::io::ErrorKind::Interrupted {
+                    continue;
+                }
+                return Err(GpioError::from(err));
+            }
+            break;
+        }
+
+        file.seek(SeekFrom::Start(0))?;
+        let mut contents = String::new();
+        file.read_to_string(&mut contents)?;
+        Ok(contents.trim().parse::<i32>()? > 0)
+    }
+
+    /// Set GPIO pin polarity
+    ///
+    /// This function configures whether a GPIO pin is active-low. The GPIO
+    /// pin and polarity are passed as arguments. The function writes `"1"` or
+    /// `"0"` to the `/sys/class/gpio/gpio{num}/active_low` file.
+    ///
+    /// # Arguments
+    ///
+    /// - `gpio_num` - An integer that represents the GPIO pin number.
+    /// - `active_low` - A boolean that is `true` when the pin is wired
+    ///   active-low.
+    ///
+    /// # Returns
+    ///
+    /// A `GpioResult` that contains the result of the operation.
+    ///
+    /// # Note
+    ///
+    /// Once set, the kernel inverts the electrical level for this pin, so
+    /// `read`/`write` keep operating on logical (asserted/deasserted) values
+    /// rather than the caller having to track the inversion manually.
+    ///
+    /// Opening the `active_low` file for writing retries through the udev
+    /// permission race described on [`retry_permission_race`] instead of
+    /// failing immediately.
+    pub fn set_active_low(gpio_num: i32, active_low: bool) -> GpioResult<()> {
+        let value: &[u8] = if active_low { b"1" } else { b"0" };
+        let path = GpioPaths::ActiveLow(gpio_num).path();
+        retry_permission_race(|| open_file(&path)).and_then(|mut file| {
+            file.write_all(value).map_err(GpioError::from)
+        })
+    }
+
+    /// List currently exported GPIO pins
+    ///
+    /// This function lists `/sys/class/gpio`, filters entries that match
+    /// `gpio<N>`, and parses out the pin numbers.
+    ///
+    /// # Returns
+    ///
+    /// A `GpioResult` that contains the exported pin numbers.
+    ///
+    /// # Note
+    ///
+    /// Useful for discovering already-exported pins before calling
+    /// [`export`], since exporting a pin twice fails with `EBUSY`.
+    pub fn get_exported() -> GpioResult<Vec<i32>> {
+        let mut pins = Vec::new();
+
+        for entry in fs::read_dir("/sys/class/gpio")? {
+            let name = entry?.file_name();
+            if let Some(num) = parse_gpio_entry_name(&name.to_string_lossy()) {
+                pins.push(num);
+            }
+        }
+
+        Ok(pins)
+    }
+
+    /// Parses a `/sys/class/gpio` entry name into its pin number.
+    ///
+    /// Returns `None` for entries that don't match `gpio<N>`, e.g.
+    /// `export`, `unexport`, or `gpiochip0`.
+    fn parse_gpio_entry_name(name: &str) -> Option<i32> {
+        let num_str = name.strip_prefix("gpio")?;
+        if num_str.is_empty() || !num_str.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        num_str.parse::<i32>().ok()
+    }
+
+    /// Read back a GPIO pin's direction
+    ///
+    /// This function reads the `/sys/class/gpio/gpio{num}/direction` file
+    /// and maps it back onto the `Directions` enum.
+    ///
+    /// # Arguments
+    ///
+    /// - `gpio_num` - An integer that represents the GPIO pin number.
+    ///
+    /// # Returns
+    ///
+    /// A `GpioResult` that contains the pin's current direction.
+    ///
+    /// # Note
+    ///
+    /// The kernel normalizes `low`/`high` back to `out` in the `direction`
+    /// file, so only `in`/`out` are read back here.
+    pub fn get_direction(gpio_num: i32) -> GpioResult<Directions> {
+        let contents = fs::read_to_string(GpioPaths::Direction(gpio_num).path())?;
+        parse_direction(contents.trim())
+    }
+
+    /// Parses the contents of a `direction` sysfs file into `Directions`.
+    fn parse_direction(contents: &str) -> GpioResult<Directions> {
+        match contents {
+            "in" => Ok(Directions::Input),
+            "out" => Ok(Directions::Output),
+            other => Err(GpioError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("unexpected direction value: {}", other),
+            ))),
+        }
+    }
+
+    /// A GPIO pin with cached file handles.
+    ///
+    /// `Pin` exports the given pin number on construction and keeps the
+    /// `value` and `direction` sysfs files open for its whole lifetime,
+    /// instead of reopening them on every `read`/`write` like the free
+    /// functions above do. Dropping a `Pin` unexports the pin automatically,
+    /// mirroring the RAII handle pattern used by sysfs GPIO libraries such as
+    /// `sysfs_gpio`.
+    pub struct Pin {
+        num: i32,
+        value: File,
+        direction: File,
+    }
+
+    impl Pin {
+        /// Exports `num` and opens its `value`/`direction` files, caching
+        /// both handles for the lifetime of the returned `Pin`. Both opens
+        /// retry through the udev permission race described on
+        /// [`retry_permission_race`] instead of failing immediately, since
+        /// `export` triggers the same "add" udev event for both files.
+        pub fn new(num: i32) -> GpioResult<Pin> {
+            export(num)?;
+            let direction = retry_permission_race(|| open_file_rw(&GpioPaths::Direction(num).path()))?;
+            let value = retry_permission_race(|| open_file_rw(&GpioPaths::Value(num).path()))?;
+
+            Ok(Pin { num, value, direction })
+        }
+
+        /// Returns the GPIO pin number this handle was created for.
+        pub fn number(&self) -> i32 {
+            self.num
+        }
+
+        /// Sets the pin direction using the cached `direction` handle.
+        pub fn set_direction(&mut self, direction: Directions) -> GpioResult<()> {
+            self.direction.seek(SeekFrom::Start(0))?;
+            self.direction.write_all(direction.as_bytes())?;
+            Ok(())
+        }
+
+        /// Writes a signal using the cached `value` handle.
+        ///
+        /// The handle is `seek`ed back to the start before every write so the
+        /// kernel always sees a fresh write rather than an append.
+        pub fn write(&mut self, signal: bool) -> GpioResult<()> {
+            let bytes: &[u8] = if signal { b"1" } else { b"0" };
+            self.value.seek(SeekFrom::Start(0))?;
+            self.value.write_all(bytes)?;
+            Ok(())
+        }
+
+        /// Reads a signal using the cached `value` handle.
+        ///
+        /// The handle is `seek`ed back to the start before every read so
+        /// repeated reads observe the current pin state rather than EOF.
+        pub fn read(&mut self) -> GpioResult<bool> {
+            self.value.seek(SeekFrom::Start(0))?;
+            let mut contents = String::new();
+            self.value.read_to_string(&mut contents)?;
+            Ok(contents.trim().parse::<i32>()? > 0)
+        }
+    }
+
+    /// Unexports the pin when its `Pin` handle goes out of scope.
+    impl Drop for Pin {
+        fn drop(&mut self) {
+            let _ = unexport(self.num);
+        }
+    }
+
+    /// `embedded-hal` digital trait impls for [`Pin`], gated behind the
+    /// `embedded-hal` cargo feature.
+    ///
+    /// Implementing these lets `Pin` drop into the broader Rust embedded
+    /// driver ecosystem (displays, sensors, LED strips) that is written
+    /// generically against `embedded-hal`, instead of requiring drivers to
+    /// target this crate's bespoke API directly.
+    #[cfg(feature = "embedded-hal")]
+    mod hal {
+        use super::{GpioError, Pin};
+        use embedded_hal::digital::{Error, ErrorKind, ErrorType, InputPin, OutputPin};
+
+        impl Error for GpioError {
+            fn kind(&self) -> ErrorKind {
+                ErrorKind::Other
+            }
+        }
+
+        impl ErrorType for Pin {
+            type Error = GpioError;
+        }
+
+        impl OutputPin for Pin {
+            fn set_low(&mut self) -> Result<(), Self::Error> {
+                self.write(false)
+            }
+
+            fn set_high(&mut self) -> Result<(), Self::Error> {
+                self.write(true)
+            }
+        }
+
+        impl InputPin for Pin {
+            fn is_high(&mut self) -> Result<bool, Self::Error> {
+                self.read()
+            }
+
+            fn is_low(&mut self) -> Result<bool, Self::Error> {
+                self.read().map(|high| !high)
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn parse_gpio_entry_name_matches_valid_pins() {
+            assert_eq!(parse_gpio_entry_name("gpio4"), Some(4));
+            assert_eq!(parse_gpio_entry_name("gpio17"), Some(17));
+        }
+
+        #[test]
+        fn parse_gpio_entry_name_rejects_non_pin_entries() {
+            assert_eq!(parse_gpio_entry_name("export"), None);
+            assert_eq!(parse_gpio_entry_name("unexport"), None);
+            assert_eq!(parse_gpio_entry_name("gpiochip0"), None);
+            assert_eq!(parse_gpio_entry_name("gpio"), None);
+            assert_eq!(parse_gpio_entry_name("gpio4x"), None);
+        }
+
+        #[test]
+        fn parse_direction_maps_known_values() {
+            assert_eq!(parse_direction("in").unwrap(), Directions::Input);
+            assert_eq!(parse_direction("out").unwrap(), Directions::Output);
+        }
+
+        #[test]
+        fn parse_direction_rejects_unknown_values() {
+            assert!(parse_direction("low").is_err());
+            assert!(parse_direction("").is_err());
+        }
+
+        #[test]
+        fn retry_permission_race_succeeds_after_transient_denials() {
+            let mut attempts = 0;
+            let result = retry_permission_race(|| {
+                attempts += 1;
+                if attempts < 3 {
+                    Err(GpioError::Io(std::io::Error::new(
+                        std::io::ErrorKind::PermissionDenied,
+                        "value file not ready yet",
+                    )))
+                } else {
+                    open_file_rw("/dev/null")
+                }
+            });
+
+            assert!(result.is_ok());
+            assert_eq!(attempts, 3);
+        }
+
+        #[test]
+        fn retry_permission_race_gives_up_after_the_budget_is_spent() {
+            let mut attempts = 0;
+            let result = retry_permission_race(|| {
+                attempts += 1;
+                Err(GpioError::Io(std::io::Error::new(
+                    std::io::ErrorKind::PermissionDenied,
+                    "value file never becomes writable",
+                )))
+            });
+
+            assert!(matches!(result, Err(GpioError::PermissionRetryExhausted)));
+            assert!(attempts > 1);
+        }
+
+        #[test]
+        fn retry_permission_race_propagates_other_errors_immediately() {
+            let mut attempts = 0;
+            let result = retry_permission_race(|| {
+                attempts += 1;
+                Err(GpioError::Io(std::io::Error::new(
+                    std::io::ErrorKind::NotFound,
+                    "no such pin",
+                )))
+            });
+
+            assert!(matches!(result, Err(GpioError::Io(_))));
+            assert_eq!(attempts, 1);
+        }
+    }
+}